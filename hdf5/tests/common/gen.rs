@@ -1,3 +1,4 @@
+use std::cell::RefCell;
 use std::convert::TryFrom;
 use std::fmt::{self, Debug};
 use std::iter;
@@ -11,13 +12,272 @@ use hdf5_metno as hdf5;
 use half::f16;
 use ndarray::{ArrayD, SliceInfo, SliceInfoElem};
 use num_complex::Complex;
+use rand::distr::weighted::WeightedIndex;
 use rand::distr::StandardUniform;
 use rand::distr::{Alphanumeric, Uniform};
 use rand::prelude::Rng;
 use rand::prelude::{Distribution, IndexedRandom};
+use rand::RngCore;
+
+/// Tuning for primitive generation: with the given probability a "special"
+/// edge-case value is emitted instead of a plain uniform draw, biasing the
+/// stream toward the values most likely to break type conversion/filtering.
+#[derive(Clone, Copy, Debug)]
+pub struct GenConfig {
+    /// Probability of emitting a special float (NaN, infinities, signed zero,
+    /// subnormal, `MIN`/`MAX`).
+    pub float_special_prob: f64,
+    /// Probability of emitting a special integer (`0`, `1`, `-1`, `MIN`, `MAX`).
+    pub int_special_prob: f64,
+}
+
+impl Default for GenConfig {
+    fn default() -> Self {
+        Self { float_special_prob: 0.25, int_special_prob: 0.25 }
+    }
+}
+
+thread_local! {
+    static GEN_CONFIG: RefCell<GenConfig> = RefCell::new(GenConfig::default());
+}
+
+/// The configuration in effect for the current thread.
+pub fn gen_config() -> GenConfig {
+    GEN_CONFIG.with(|c| *c.borrow())
+}
+
+/// Run `f` with `cfg` installed as the current thread's [`GenConfig`],
+/// restoring the previous configuration afterwards.
+pub fn with_gen_config<T>(cfg: GenConfig, f: impl FnOnce() -> T) -> T {
+    struct Restore(GenConfig);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            GEN_CONFIG.with(|c| *c.borrow_mut() = self.0);
+        }
+    }
+    // Restores the previous config even if `f` unwinds.
+    let _restore = Restore(GEN_CONFIG.with(|c| c.replace(cfg)));
+    f()
+}
+
+/// Primitive with a curated table of edge-case values for biased generation.
+trait BiasedPrim: Copy + 'static {
+    fn specials() -> &'static [Self];
+    fn special_prob(cfg: &GenConfig) -> f64;
+}
+
+macro_rules! impl_biased_uint {
+    ($($ty:ty),+) => {$(
+        impl BiasedPrim for $ty {
+            fn specials() -> &'static [Self] {
+                &[0, 1, <$ty>::MAX]
+            }
+            fn special_prob(cfg: &GenConfig) -> f64 {
+                cfg.int_special_prob
+            }
+        }
+    )+};
+}
+
+macro_rules! impl_biased_int {
+    ($($ty:ty),+) => {$(
+        impl BiasedPrim for $ty {
+            fn specials() -> &'static [Self] {
+                &[0, 1, -1, <$ty>::MIN, <$ty>::MAX]
+            }
+            fn special_prob(cfg: &GenConfig) -> f64 {
+                cfg.int_special_prob
+            }
+        }
+    )+};
+}
+
+macro_rules! impl_biased_float {
+    ($($ty:ty),+) => {$(
+        impl BiasedPrim for $ty {
+            fn specials() -> &'static [Self] {
+                &[
+                    <$ty>::NAN,
+                    <$ty>::INFINITY,
+                    <$ty>::NEG_INFINITY,
+                    0.0,
+                    -0.0,
+                    <$ty>::MIN,
+                    <$ty>::MAX,
+                    <$ty>::from_bits(1),
+                ]
+            }
+            fn special_prob(cfg: &GenConfig) -> f64 {
+                cfg.float_special_prob
+            }
+        }
+    )+};
+}
+
+impl_biased_uint!(u8, u16, u32, u64);
+impl_biased_int!(i8, i16, i32, i64);
+impl_biased_float!(f32, f64);
+
+/// Draw a primitive, consulting the current [`GenConfig`] for a Bernoulli trial
+/// that, when it succeeds, returns a uniformly chosen edge-case value.
+fn gen_biased<R: Rng + ?Sized, T: BiasedPrim>(rng: &mut R) -> T
+where
+    StandardUniform: Distribution<T>,
+{
+    let p = T::special_prob(&gen_config()).clamp(0.0, 1.0);
+    if p > 0.0 && rng.random_bool(p) {
+        *T::specials().choose(rng).unwrap()
+    } else {
+        rng.random()
+    }
+}
+
+/// Deterministic, portable PRNG used to make the random roundtrip suite
+/// reproducible. It is a xoshiro256** generator seeded from a single `u64`
+/// (expanded through SplitMix64), so the same seed yields the same stream on
+/// every platform and architecture.
+pub struct TestRng {
+    s: [u64; 4],
+}
+
+impl TestRng {
+    /// Seed the generator from a single `u64`.
+    pub fn seed_from_u64(seed: u64) -> Self {
+        // SplitMix64 to spread the seed across the four state words.
+        let mut x = seed;
+        let mut next = || {
+            x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        };
+        Self { s: [next(), next(), next(), next()] }
+    }
+}
+
+impl RngCore for TestRng {
+    fn next_u64(&mut self) -> u64 {
+        let result = self.s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.s[1] << 17;
+        self.s[2] ^= self.s[0];
+        self.s[3] ^= self.s[1];
+        self.s[1] ^= self.s[2];
+        self.s[0] ^= self.s[3];
+        self.s[2] ^= t;
+        self.s[3] = self.s[3].rotate_left(45);
+        result
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for chunk in dst.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+
+/// Run `f` with a freshly seeded [`TestRng`], logging the seed so the run can
+/// be replayed. The seed is read from the `HDF5_TEST_SEED` environment variable
+/// when set, otherwise drawn from entropy. On panic the seed is re-printed with
+/// instructions for reproducing the failure.
+pub fn run_seeded<T>(label: &str, f: impl FnOnce(&mut TestRng) -> T) -> T {
+    let seed = match std::env::var("HDF5_TEST_SEED") {
+        Ok(s) => s.trim().parse().expect("HDF5_TEST_SEED must be a u64"),
+        Err(_) => rand::rng().random(),
+    };
+    eprintln!("[{label}] seed={seed}");
+
+    let mut rng = TestRng::seed_from_u64(seed);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(&mut rng)));
+    match result {
+        Ok(value) => value,
+        Err(payload) => {
+            eprintln!("[{label}] FAILED; replay with HDF5_TEST_SEED={seed}");
+            std::panic::resume_unwind(payload);
+        }
+    }
+}
+
+/// How [`gen_shape_with`] draws dimension sizes.
+#[derive(Clone, Copy, Debug)]
+pub enum ShapeStrategy {
+    /// Each dimension drawn uniformly from `0..11` (the historical default).
+    Uniform,
+    /// Small sizes are common, but a configurable fat tail occasionally
+    /// produces dimensions in the thousands to exercise the chunked-I/O,
+    /// large-allocation and compression-filter paths. `max_elems` optionally
+    /// caps the total element count to bound memory.
+    HeavyTailed { tail_prob: f64, max_dim: usize, max_elems: Option<usize> },
+}
+
+impl Default for ShapeStrategy {
+    fn default() -> Self {
+        ShapeStrategy::Uniform
+    }
+}
 
 pub fn gen_shape<R: Rng + ?Sized>(rng: &mut R, ndim: usize) -> Vec<usize> {
-    iter::repeat(()).map(|_| rng.random_range(0..11)).take(ndim).collect()
+    gen_shape_with(rng, ndim, ShapeStrategy::Uniform)
+}
+
+/// Generate a shape of `ndim` dimensions according to `strategy`.
+pub fn gen_shape_with<R: Rng + ?Sized>(
+    rng: &mut R, ndim: usize, strategy: ShapeStrategy,
+) -> Vec<usize> {
+    match strategy {
+        ShapeStrategy::Uniform => {
+            iter::repeat(()).map(|_| rng.random_range(0..11)).take(ndim).collect()
+        }
+        ShapeStrategy::HeavyTailed { tail_prob, max_dim, max_elems } => {
+            let mut shape: Vec<usize> = iter::repeat(())
+                .map(|_| {
+                    if rng.random_bool(tail_prob.clamp(0.0, 1.0)) {
+                        rng.random_range(1..=max_dim.max(1))
+                    } else {
+                        gen_geometric(rng)
+                    }
+                })
+                .take(ndim)
+                .collect();
+            if let Some(cap) = max_elems {
+                cap_elements(&mut shape, cap);
+            }
+            shape
+        }
+    }
+}
+
+/// Draw a small, geometrically distributed dimension size: 0 is common, larger
+/// values fall off quickly (mean ~1).
+fn gen_geometric<R: Rng + ?Sized>(rng: &mut R) -> usize {
+    let mut n = 0;
+    while rng.random_bool(0.5) {
+        n += 1;
+    }
+    n
+}
+
+/// Shrink `shape` in place until its total element count fits within `cap`,
+/// repeatedly halving the largest dimension.
+fn cap_elements(shape: &mut [usize], cap: usize) {
+    loop {
+        let within = shape
+            .iter()
+            .try_fold(1usize, |acc, &d| acc.checked_mul(d))
+            .is_some_and(|product| product <= cap);
+        if within {
+            break;
+        }
+        match shape.iter_mut().filter(|d| **d > 0).max_by_key(|d| **d) {
+            Some(largest) => *largest /= 2,
+            None => break,
+        }
+    }
 }
 
 pub fn gen_ascii<R: Rng + ?Sized>(rng: &mut R, len: usize) -> String {
@@ -62,13 +322,116 @@ fn gen_slice_one_dim<R: Rng + ?Sized>(rng: &mut R, shape: usize) -> ndarray::Sli
 
 pub trait Gen: Sized + fmt::Debug {
     fn random<R: Rng + ?Sized>(rng: &mut R) -> Self;
+
+    /// Produce progressively "smaller" variants of `self`, used to minimize a
+    /// failing counterexample. The default yields nothing, i.e. the value is
+    /// already considered minimal.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(iter::empty())
+    }
+}
+
+/// Greedy delta-debugging minimizer: repeatedly pull candidates from
+/// `failing.shrink()` and, whenever a candidate still makes `pred` return
+/// `true` (still fails), adopt it and restart the shrink iterator. Stops once
+/// no candidate fails and returns the minimal still-failing value.
+pub fn minimize<T: Gen>(failing: T, pred: impl Fn(&T) -> bool) -> T {
+    let mut current = failing;
+    'outer: loop {
+        for candidate in current.shrink() {
+            if pred(&candidate) {
+                current = candidate;
+                continue 'outer;
+            }
+        }
+        return current;
+    }
 }
 
+/// Shrinking of a single primitive value, shared by the `Gen` primitive impls.
+trait ShrinkPrim: Copy {
+    fn shrink_prim(self) -> Vec<Self>;
+}
+
+macro_rules! impl_shrink_int {
+    ($($ty:ty),+) => {$(
+        impl ShrinkPrim for $ty {
+            fn shrink_prim(self) -> Vec<Self> {
+                if self == 0 {
+                    return Vec::new();
+                }
+                // Halve the distance toward 0, largest steps first.
+                let mut out = vec![0 as $ty];
+                let mut delta = self / 2;
+                while delta != 0 {
+                    let candidate = self - delta;
+                    if candidate != self {
+                        out.push(candidate);
+                    }
+                    delta /= 2;
+                }
+                out
+            }
+        }
+    )+};
+}
+
+impl_shrink_int!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl ShrinkPrim for bool {
+    fn shrink_prim(self) -> Vec<Self> {
+        if self { vec![false] } else { Vec::new() }
+    }
+}
+
+macro_rules! impl_shrink_float {
+    ($($ty:ty),+) => {$(
+        impl ShrinkPrim for $ty {
+            fn shrink_prim(self) -> Vec<Self> {
+                // NaN and the zeroes collapse directly toward +0.0.
+                if self.is_nan() {
+                    return vec![0.0];
+                }
+                if self == 0.0 {
+                    return if self.is_sign_negative() { vec![0.0] } else { Vec::new() };
+                }
+                let mut out = vec![0.0];
+                if self.is_infinite() {
+                    out.push(<$ty>::MAX.copysign(self));
+                    return out;
+                }
+                // Drop the sign bit, then the fractional part, then halve.
+                if self < 0.0 {
+                    out.push(-self);
+                }
+                let truncated = self.trunc();
+                if truncated != self {
+                    out.push(truncated);
+                }
+                let mut current = self / 2.0;
+                for _ in 0..16 {
+                    if current == 0.0 || !current.is_finite() {
+                        break;
+                    }
+                    out.push(current);
+                    current /= 2.0;
+                }
+                out
+            }
+        }
+    )+};
+}
+
+impl_shrink_float!(f32, f64);
+
 macro_rules! impl_gen_primitive {
     ($ty:ty) => {
         impl Gen for $ty {
             fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
-                rng.random()
+                gen_biased(rng)
+            }
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                Box::new(self.shrink_prim().into_iter())
             }
         }
     };
@@ -78,7 +441,16 @@ macro_rules! impl_gen_primitive {
     };
 }
 
-impl_gen_primitive!(u8, u16, u32, u64, i8, i16, i32, i64, bool, f32, f64);
+impl_gen_primitive!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+impl Gen for bool {
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.random()
+    }
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.shrink_prim().into_iter())
+    }
+}
 
 macro_rules! impl_gen_tuple {
     ($t:ident) => (
@@ -103,16 +475,54 @@ impl_gen_tuple! { A, B, C, D, E, F, G, H, I, J, K, L }
 
 impl Gen for f16 {
     fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
-        Self::from_f32(rng.random())
+        // Route through the same biased path, with an f16-native edge-case table.
+        let p = gen_config().float_special_prob.clamp(0.0, 1.0);
+        if p > 0.0 && rng.random_bool(p) {
+            let specials = [
+                f16::NAN,
+                f16::INFINITY,
+                f16::NEG_INFINITY,
+                f16::from_f32(0.0),
+                f16::from_f32(-0.0),
+                f16::MIN,
+                f16::MAX,
+                f16::from_bits(1),
+            ];
+            *specials.choose(rng).unwrap()
+        } else {
+            Self::from_f32(rng.random())
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        // Shrink through the wider f32 and fold back to f16, dropping any
+        // candidate that rounds back to `self` so `minimize` cannot loop.
+        let this = *self;
+        let shrunk: Vec<f16> = this
+            .to_f32()
+            .shrink_prim()
+            .into_iter()
+            .map(f16::from_f32)
+            .filter(move |c| c.to_bits() != this.to_bits())
+            .collect();
+        Box::new(shrunk.into_iter())
     }
 }
 
-impl<T: Debug> Gen for Complex<T>
+impl<T: Gen + Clone + 'static> Gen for Complex<T>
 where
     StandardUniform: Distribution<T>,
 {
     fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
-        Self::new(rng.random(), rng.random())
+        Self::new(T::random(rng), T::random(rng))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let im = self.im.clone();
+        let re = self.re.clone();
+        let shrink_re = self.re.shrink().map(move |r| Complex::new(r, im.clone()));
+        let shrink_im = self.im.shrink().map(move |i| Complex::new(re.clone(), i));
+        Box::new(shrink_re.chain(shrink_im))
     }
 }
 
@@ -131,6 +541,206 @@ where
     ArrayD::from_shape_vec(shape, vec).unwrap()
 }
 
+/// Shrink an array by first trimming its shape toward the degenerate case
+/// (halve each dimension toward 1, then drop a length-1 axis entirely) and
+/// then shrinking the elements one at a time.
+pub fn shrink_arr<T>(arr: &ArrayD<T>) -> Vec<ArrayD<T>>
+where
+    T: Gen + Clone,
+{
+    let mut out = Vec::new();
+    let shape = arr.shape().to_vec();
+
+    for (axis, &dim) in shape.iter().enumerate() {
+        if dim > 1 {
+            let end = dim.div_ceil(2);
+            out.push(arr.slice_axis(ndarray::Axis(axis), ndarray::Slice::from(0..end)).to_owned());
+        } else if dim == 1 {
+            // A length-1 axis carries no information: drop it, lowering the rank.
+            out.push(arr.index_axis(ndarray::Axis(axis), 0).to_owned());
+        }
+    }
+
+    let flat: Vec<T> = arr.iter().cloned().collect();
+    for (i, elem) in flat.iter().enumerate() {
+        for s in elem.shrink() {
+            let mut v = flat.clone();
+            v[i] = s;
+            if let Ok(a) = ArrayD::from_shape_vec(shape.clone(), v) {
+                out.push(a);
+            }
+        }
+    }
+
+    out
+}
+
+/// Equality for round-trip checks: like `PartialEq`, but compares floats by
+/// their bit pattern so that an exact, bit-preserving round-trip of a special
+/// value (NaN, signed zero, ...) verifies as equal rather than spuriously
+/// failing on `NaN != NaN` or passing on `-0.0 == 0.0`.
+trait RoundtripEq {
+    fn roundtrip_eq(&self, other: &Self) -> bool;
+}
+
+macro_rules! impl_roundtrip_eq_partial {
+    ($($ty:ty),+) => {$(
+        impl RoundtripEq for $ty {
+            fn roundtrip_eq(&self, other: &Self) -> bool {
+                self == other
+            }
+        }
+    )+};
+}
+
+impl_roundtrip_eq_partial!(
+    u8, u16, u32, u64, i8, i16, i32, i64, bool, Enum, TupleStruct, FixedStruct, VarLenStruct,
+    RenameStruct, RenameTupleStruct, RenameEnum
+);
+
+impl RoundtripEq for f32 {
+    fn roundtrip_eq(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+}
+
+impl RoundtripEq for f64 {
+    fn roundtrip_eq(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+}
+
+impl RoundtripEq for f16 {
+    fn roundtrip_eq(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+}
+
+impl<T: RoundtripEq> RoundtripEq for Complex<T> {
+    fn roundtrip_eq(&self, other: &Self) -> bool {
+        self.re.roundtrip_eq(&other.re) && self.im.roundtrip_eq(&other.im)
+    }
+}
+
+impl RoundtripEq for (i32, f64) {
+    fn roundtrip_eq(&self, other: &Self) -> bool {
+        self.0.roundtrip_eq(&other.0) && self.1.roundtrip_eq(&other.1)
+    }
+}
+
+impl RoundtripEq for (u8, f32, i16) {
+    fn roundtrip_eq(&self, other: &Self) -> bool {
+        self.0.roundtrip_eq(&other.0)
+            && self.1.roundtrip_eq(&other.1)
+            && self.2.roundtrip_eq(&other.2)
+    }
+}
+
+impl<T: RoundtripEq + Copy> RoundtripEq for VarLenArray<T> {
+    fn roundtrip_eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self.iter().zip(other.iter()).all(|(a, b)| a.roundtrip_eq(b))
+    }
+}
+
+/// A type-erased generated value that can round-trip itself through a file.
+pub trait AnyGen {
+    /// Write this value to `file` as a dataset, read it back, and report
+    /// whether the round-trip preserved it exactly.
+    fn write_read_roundtrip(&self, file: &hdf5::File) -> bool;
+}
+
+struct GenArray<T> {
+    name: String,
+    data: ArrayD<T>,
+}
+
+impl<T> AnyGen for GenArray<T>
+where
+    T: H5Type + Clone + RoundtripEq + Debug,
+{
+    fn write_read_roundtrip(&self, file: &hdf5::File) -> bool {
+        let ds = match file.new_dataset::<T>().shape(self.data.shape()).create(self.name.as_str()) {
+            Ok(ds) => ds,
+            Err(_) => return false,
+        };
+        if ds.write(self.data.view()).is_err() {
+            return false;
+        }
+        match ds.read_dyn::<T>() {
+            Ok(read) => {
+                read.shape() == self.data.shape()
+                    && read.iter().zip(self.data.iter()).all(|(a, b)| a.roundtrip_eq(b))
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+fn make_any<R, T>(rng: &mut R, ndim: usize, name: String) -> Box<dyn AnyGen>
+where
+    R: Rng + ?Sized,
+    T: H5Type + Gen + Clone + RoundtripEq + Debug + 'static,
+{
+    Box::new(GenArray { name, data: gen_arr::<T, R>(rng, ndim) })
+}
+
+/// Generate a random value of a random `H5Type`, boxed behind [`AnyGen`], using
+/// a weighted selection over a fixed list of types: the integer and float
+/// primitives, `bool`, `f16`, `Complex<f32>`/`Complex<f64>`, the tuples
+/// `(i32, f64)` and `(u8, f32, i16)`, the enum and struct types of this module,
+/// and `VarLenArray<u32>`. Other tuple arities are not generated. Rare or
+/// expensive compound and variable-length types are down-weighted relative to
+/// the primitives, so a single loop can round-trip thousands of random datasets
+/// of random dtype and shape.
+pub fn gen_any<R: Rng + ?Sized>(rng: &mut R) -> Box<dyn AnyGen> {
+    // Weights line up 1:1 with the dispatch arms below.
+    let weights: [u32; 24] = [
+        8, 8, 8, 8, // u8 u16 u32 u64
+        8, 8, 8, 8, // i8 i16 i32 i64
+        8, // bool
+        8, 8, // f32 f64
+        6, // f16
+        6, 6, // Complex<f32> Complex<f64>
+        4, 4, // tuples
+        3, 3, // Enum, TupleStruct
+        2, 2, // FixedStruct, VarLenStruct
+        3, 3, 3, // rename structs/enum
+        4, // VarLenArray
+    ];
+    let kind = WeightedIndex::new(weights).unwrap().sample(rng);
+
+    let ndim = rng.random_range(0..4);
+    let name = format!("any_{kind}_{:x}", rng.random::<u64>());
+
+    match kind {
+        0 => make_any::<R, u8>(rng, ndim, name),
+        1 => make_any::<R, u16>(rng, ndim, name),
+        2 => make_any::<R, u32>(rng, ndim, name),
+        3 => make_any::<R, u64>(rng, ndim, name),
+        4 => make_any::<R, i8>(rng, ndim, name),
+        5 => make_any::<R, i16>(rng, ndim, name),
+        6 => make_any::<R, i32>(rng, ndim, name),
+        7 => make_any::<R, i64>(rng, ndim, name),
+        8 => make_any::<R, bool>(rng, ndim, name),
+        9 => make_any::<R, f32>(rng, ndim, name),
+        10 => make_any::<R, f64>(rng, ndim, name),
+        11 => make_any::<R, f16>(rng, ndim, name),
+        12 => make_any::<R, Complex<f32>>(rng, ndim, name),
+        13 => make_any::<R, Complex<f64>>(rng, ndim, name),
+        14 => make_any::<R, (i32, f64)>(rng, ndim, name),
+        15 => make_any::<R, (u8, f32, i16)>(rng, ndim, name),
+        16 => make_any::<R, Enum>(rng, ndim, name),
+        17 => make_any::<R, TupleStruct>(rng, ndim, name),
+        18 => make_any::<R, FixedStruct>(rng, ndim, name),
+        19 => make_any::<R, VarLenStruct>(rng, ndim, name),
+        20 => make_any::<R, RenameStruct>(rng, ndim, name),
+        21 => make_any::<R, RenameTupleStruct>(rng, ndim, name),
+        22 => make_any::<R, RenameEnum>(rng, ndim, name),
+        _ => make_any::<R, VarLenArray<u32>>(rng, ndim, name),
+    }
+}
+
 impl<const N: usize> Gen for FixedAscii<N> {
     fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
         let len = rng.sample(Uniform::new_inclusive(0, N).unwrap());
@@ -141,6 +751,19 @@ impl<const N: usize> Gen for FixedAscii<N> {
         }
         unsafe { FixedAscii::from_ascii_unchecked(&v) }
     }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        // Dropping a byte keeps the value ASCII and strictly within `N`.
+        let bytes = self.as_bytes().to_vec();
+        let candidates: Vec<Self> = (0..bytes.len())
+            .map(|i| {
+                let mut v = bytes.clone();
+                v.remove(i);
+                unsafe { FixedAscii::from_ascii_unchecked(&v) }
+            })
+            .collect();
+        Box::new(candidates.into_iter())
+    }
 }
 
 impl<const N: usize> Gen for FixedAsciiOdim<N> {
@@ -153,6 +776,18 @@ impl<const N: usize> Gen for FixedAsciiOdim<N> {
         }
         unsafe { FixedAsciiOdim::from_ascii_unchecked(&v) }
     }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let bytes = self.as_bytes().to_vec();
+        let candidates: Vec<Self> = (0..bytes.len())
+            .map(|i| {
+                let mut v = bytes.clone();
+                v.remove(i);
+                unsafe { FixedAsciiOdim::from_ascii_unchecked(&v) }
+            })
+            .collect();
+        Box::new(candidates.into_iter())
+    }
 }
 
 impl<const N: usize> Gen for FixedUnicode<N> {
@@ -170,6 +805,21 @@ impl<const N: usize> Gen for FixedUnicode<N> {
         }
         unsafe { FixedUnicode::from_str_unchecked(s) }
     }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        // Dropping a whole `char` keeps valid UTF-8 and strictly shrinks.
+        let s = self.as_str().to_owned();
+        let candidates: Vec<Self> = s
+            .char_indices()
+            .map(|(i, c)| {
+                let mut t = String::with_capacity(s.len() - c.len_utf8());
+                t.push_str(&s[..i]);
+                t.push_str(&s[i + c.len_utf8()..]);
+                unsafe { FixedUnicode::from_str_unchecked(t) }
+            })
+            .collect();
+        Box::new(candidates.into_iter())
+    }
 }
 
 impl Gen for VarLenAscii {
@@ -182,6 +832,18 @@ impl Gen for VarLenAscii {
         }
         unsafe { VarLenAscii::from_ascii_unchecked(&v) }
     }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let bytes = self.as_bytes().to_vec();
+        let candidates: Vec<Self> = (0..bytes.len())
+            .map(|i| {
+                let mut v = bytes.clone();
+                v.remove(i);
+                unsafe { VarLenAscii::from_ascii_unchecked(&v) }
+            })
+            .collect();
+        Box::new(candidates.into_iter())
+    }
 }
 
 impl Gen for VarLenUnicode {
@@ -196,9 +858,23 @@ impl Gen for VarLenUnicode {
         }
         unsafe { VarLenUnicode::from_str_unchecked(s) }
     }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let s = self.as_str().to_owned();
+        let candidates: Vec<Self> = s
+            .char_indices()
+            .map(|(i, c)| {
+                let mut t = String::with_capacity(s.len() - c.len_utf8());
+                t.push_str(&s[..i]);
+                t.push_str(&s[i + c.len_utf8()..]);
+                unsafe { VarLenUnicode::from_str_unchecked(t) }
+            })
+            .collect();
+        Box::new(candidates.into_iter())
+    }
 }
 
-impl<T: Gen + Copy> Gen for VarLenArray<T> {
+impl<T: Gen + Copy + 'static> Gen for VarLenArray<T> {
     fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
         let len = rng.sample(Uniform::new_inclusive(0, 8).unwrap());
         let mut v = Vec::with_capacity(len);
@@ -207,6 +883,25 @@ impl<T: Gen + Copy> Gen for VarLenArray<T> {
         }
         VarLenArray::from_slice(&v)
     }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let items: Vec<T> = self.iter().copied().collect();
+        let mut candidates = Vec::new();
+        // First drop elements, then shrink the survivors element-wise.
+        for i in 0..items.len() {
+            let mut v = items.clone();
+            v.remove(i);
+            candidates.push(VarLenArray::from_slice(&v));
+        }
+        for (i, elem) in items.iter().enumerate() {
+            for s in elem.shrink() {
+                let mut v = items.clone();
+                v[i] = s;
+                candidates.push(VarLenArray::from_slice(&v));
+            }
+        }
+        Box::new(candidates.into_iter())
+    }
 }
 
 #[derive(H5Type, Clone, Copy, Debug, PartialEq)]
@@ -220,6 +915,14 @@ impl Gen for Enum {
     fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
         *[Enum::X, Enum::Y].choose(rng).unwrap()
     }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        // Stay within the valid discriminant set, moving toward the first variant.
+        match self {
+            Enum::Y => Box::new(iter::once(Enum::X)),
+            Enum::X => Box::new(iter::empty()),
+        }
+    }
 }
 
 #[derive(H5Type, Clone, Copy, Debug, PartialEq)]
@@ -230,6 +933,13 @@ impl Gen for TupleStruct {
     fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
         TupleStruct(Gen::random(rng), Gen::random(rng))
     }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let TupleStruct(b, e) = *self;
+        let shrink_b = b.shrink().map(move |b| TupleStruct(b, e));
+        let shrink_e = e.shrink().map(move |e| TupleStruct(b, e));
+        Box::new(shrink_b.chain(shrink_e))
+    }
 }
 
 #[derive(H5Type, Clone, Debug, PartialEq)]
@@ -250,6 +960,27 @@ impl Gen for FixedStruct {
             array: [Gen::random(rng), Gen::random(rng)],
         }
     }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let mut out = Vec::new();
+        for fa in self.fa.shrink() {
+            out.push(FixedStruct { fa, ..self.clone() });
+        }
+        for fao in self.fao.shrink() {
+            out.push(FixedStruct { fao, ..self.clone() });
+        }
+        for fu in self.fu.shrink() {
+            out.push(FixedStruct { fu, ..self.clone() });
+        }
+        for (i, el) in self.array.iter().enumerate() {
+            for s in el.shrink() {
+                let mut array = self.array;
+                array[i] = s;
+                out.push(FixedStruct { array, ..self.clone() });
+            }
+        }
+        Box::new(out.into_iter())
+    }
 }
 
 #[derive(H5Type, Clone, Debug, PartialEq)]
@@ -264,6 +995,20 @@ impl Gen for VarLenStruct {
     fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
         VarLenStruct { va: Gen::random(rng), vu: Gen::random(rng), vla: Gen::random(rng) }
     }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let mut out = Vec::new();
+        for va in self.va.shrink() {
+            out.push(VarLenStruct { va, ..self.clone() });
+        }
+        for vu in self.vu.shrink() {
+            out.push(VarLenStruct { vu, ..self.clone() });
+        }
+        for vla in self.vla.shrink() {
+            out.push(VarLenStruct { vla, ..self.clone() });
+        }
+        Box::new(out.into_iter())
+    }
 }
 
 #[derive(H5Type, Clone, Debug, PartialEq)]
@@ -278,6 +1023,17 @@ impl Gen for RenameStruct {
     fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
         RenameStruct { first: Gen::random(rng), second: Gen::random(rng) }
     }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let mut out = Vec::new();
+        for first in self.first.shrink() {
+            out.push(RenameStruct { first, ..self.clone() });
+        }
+        for second in self.second.shrink() {
+            out.push(RenameStruct { second, ..self.clone() });
+        }
+        Box::new(out.into_iter())
+    }
 }
 
 #[derive(H5Type, Clone, Copy, Debug, PartialEq)]
@@ -288,6 +1044,13 @@ impl Gen for RenameTupleStruct {
     fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
         RenameTupleStruct(Gen::random(rng), Gen::random(rng))
     }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let RenameTupleStruct(b, e) = *self;
+        let shrink_b = b.shrink().map(move |b| RenameTupleStruct(b, e));
+        let shrink_e = e.shrink().map(move |e| RenameTupleStruct(b, e));
+        Box::new(shrink_b.chain(shrink_e))
+    }
 }
 
 #[derive(H5Type, Clone, Copy, Debug, PartialEq)]
@@ -303,4 +1066,11 @@ impl Gen for RenameEnum {
     fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
         *[RenameEnum::X, RenameEnum::Y].choose(rng).unwrap()
     }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self {
+            RenameEnum::Y => Box::new(iter::once(RenameEnum::X)),
+            RenameEnum::X => Box::new(iter::empty()),
+        }
+    }
 }